@@ -0,0 +1,77 @@
+use std::path::Path;
+
+use alloy_primitives::U256;
+use reth::revm::cached::CachedReads;
+use reth_provider::{DatabaseProviderFactory, StateProviderFactory};
+use serde::Deserialize;
+
+/// A captured order from a `bench-build` fixture file. Carries only the raw encoded
+/// transaction/bundle bytes the real order-pool would have handed the builder; decoding into
+/// the live order types happens the same way it does for orders received over the wire.
+#[derive(Debug, Clone, Deserialize)]
+pub struct FixtureOrder {
+    pub raw: Vec<u8>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct FixtureFile {
+    parent_block_number: u64,
+    orders: Vec<FixtureOrder>,
+}
+
+/// Everything the real building algorithm needs to simulate/build a single slot: the orders
+/// considered for inclusion plus a handle to chain state at the parent block. Used both for
+/// backtesting and for `bench-build`'s fixture/historical-slot replay.
+pub struct BacktestSimulateBlockInput<'a, P> {
+    pub provider: &'a P,
+    pub parent_block_number: u64,
+    pub cached_reads: Option<CachedReads>,
+    pub orders: Vec<FixtureOrder>,
+}
+
+impl<'a, P> BacktestSimulateBlockInput<'a, P>
+where
+    P: DatabaseProviderFactory + StateProviderFactory + Clone + 'static,
+{
+    /// Loads a single captured slot fixture (JSON) and binds it to a live `provider`.
+    ///
+    /// Deliberately meant to be reloaded per iteration rather than cloned: the input borrows
+    /// `provider`, so there's no cheap-to-clone snapshot to share between runs. `cached_reads`
+    /// starts `None` here; callers that want warmup to actually prime it (e.g. `bench-build`)
+    /// carry the `CachedReads` returned by the previous build forward into the next freshly
+    /// loaded input rather than leaving it `None` on every iteration.
+    pub fn load_fixture(path: &Path, provider: &'a P) -> eyre::Result<Self> {
+        let bytes = std::fs::read(path)
+            .map_err(|err| eyre::eyre!("failed to read fixture {}: {err}", path.display()))?;
+        let fixture: FixtureFile = serde_json::from_slice(&bytes)
+            .map_err(|err| eyre::eyre!("invalid fixture {}: {err}", path.display()))?;
+        Ok(Self {
+            provider,
+            parent_block_number: fixture.parent_block_number,
+            cached_reads: None,
+            orders: fixture.orders,
+        })
+    }
+
+    /// Builds an input for a historical block number with no captured orders.
+    ///
+    /// Useful for `bench-build --slot-range`, which only has block numbers to go on (no
+    /// captured order set): it exercises the algorithm's provider-state-reading path and
+    /// per-slot overhead, but profit/gas numbers from it aren't comparable to a `--fixtures-dir`
+    /// run that replays real orders.
+    pub fn for_historical_slot(parent_block_number: u64, provider: &'a P) -> Self {
+        Self { provider, parent_block_number, cached_reads: None, orders: Vec::new() }
+    }
+}
+
+/// A built block and the trace of how it was assembled, returned by `build_backtest_block`.
+pub struct Block {
+    pub trace: BlockBuildingTrace,
+}
+
+/// Summary stats for a single built block; `bench-build` reports the mean `bid_value` and
+/// mean `gas_used` over all benchmarked slots.
+pub struct BlockBuildingTrace {
+    pub bid_value: U256,
+    pub gas_used: u64,
+}
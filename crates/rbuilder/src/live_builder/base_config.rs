@@ -0,0 +1,322 @@
+use std::{net::SocketAddr, path::PathBuf, time::Duration};
+
+use serde::{de::DeserializeOwned, Deserialize};
+use tracing_subscriber::{reload, util::SubscriberInitExt, EnvFilter, Layer};
+
+use super::cli::{BaseCliArgs, L1CliArgs};
+
+type BoxedFmtLayer = Box<dyn Layer<tracing_subscriber::Registry> + Send + Sync>;
+
+/// Handle returned by [`BaseConfig::setup_tracing_subscriber`] that lets a `SIGHUP` hot-reload
+/// change the active log level and/or format (JSON vs. plain) without restarting the process.
+#[derive(Clone)]
+pub struct LogReloadHandle {
+    filter: reload::Handle<EnvFilter, tracing_subscriber::Registry>,
+    format: reload::Handle<BoxedFmtLayer, tracing_subscriber::Registry>,
+    log_color: bool,
+}
+
+impl LogReloadHandle {
+    pub fn reload_filter(&self, filter: EnvFilter) -> eyre::Result<()> {
+        self.filter.reload(filter).map_err(|err| eyre::eyre!(err))
+    }
+
+    pub fn reload_format(&self, log_json: bool) -> eyre::Result<()> {
+        self.format.reload(Self::fmt_layer(log_json, self.log_color)).map_err(|err| eyre::eyre!(err))
+    }
+
+    fn fmt_layer(log_json: bool, log_color: bool) -> BoxedFmtLayer {
+        if log_json {
+            tracing_subscriber::fmt::layer().json().boxed()
+        } else {
+            tracing_subscriber::fmt::layer().with_ansi(log_color).boxed()
+        }
+    }
+}
+
+/// Config shared by every `rbuilder` binary, populated from the TOML config file and then
+/// overridden field-by-field by whatever was passed on the CLI (see [`MergeFromCli`]).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct BaseConfig {
+    pub log_json: bool,
+    pub log_level: String,
+    pub full_telemetry_server_port: u16,
+    pub full_telemetry_server_ip: String,
+    pub redacted_telemetry_server_port: u16,
+    pub redacted_telemetry_server_ip: String,
+    pub log_color: bool,
+    pub log_enable_dynamic: bool,
+    pub error_storage_path: Option<PathBuf>,
+    pub coinbase_secret_key: Option<String>,
+    pub flashbots_db: Option<String>,
+    pub jsonrpc_server_port: u16,
+    pub jsonrpc_server_ip: String,
+    pub ignore_cancellable_orders: bool,
+    pub ignore_blobs: bool,
+    pub chain: String,
+    pub reth_datadir: PathBuf,
+    pub building_algorithm: String,
+
+    pub profile_frequency: i32,
+    pub profile_duration: Option<u64>,
+    pub profile_output: PathBuf,
+
+    pub alert_webhook_url: Option<String>,
+    pub alert_matrix_homeserver_url: Option<String>,
+    pub alert_matrix_room: Option<String>,
+    pub alert_matrix_token: Option<String>,
+
+    pub prometheus_server_enable: bool,
+    pub prometheus_server_port: u16,
+    pub prometheus_server_ip: String,
+}
+
+impl Default for BaseConfig {
+    fn default() -> Self {
+        Self {
+            log_json: false,
+            log_level: "info".to_string(),
+            full_telemetry_server_port: 6069,
+            full_telemetry_server_ip: "127.0.0.1".to_string(),
+            redacted_telemetry_server_port: 6070,
+            redacted_telemetry_server_ip: "0.0.0.0".to_string(),
+            log_color: false,
+            log_enable_dynamic: false,
+            error_storage_path: None,
+            coinbase_secret_key: None,
+            flashbots_db: None,
+            jsonrpc_server_port: 8645,
+            jsonrpc_server_ip: "127.0.0.1".to_string(),
+            ignore_cancellable_orders: false,
+            ignore_blobs: false,
+            chain: "mainnet".to_string(),
+            reth_datadir: PathBuf::from("/tmp/reth-datadir"),
+            building_algorithm: "order-taking".to_string(),
+            profile_frequency: 1000,
+            profile_duration: None,
+            profile_output: PathBuf::from("/tmp/rbuilder-flamegraph.svg"),
+            alert_webhook_url: None,
+            alert_matrix_homeserver_url: None,
+            alert_matrix_room: None,
+            alert_matrix_token: None,
+            prometheus_server_enable: false,
+            prometheus_server_port: 9090,
+            prometheus_server_ip: "0.0.0.0".to_string(),
+        }
+    }
+}
+
+impl BaseConfig {
+    pub fn redacted_telemetry_server_address(&self) -> SocketAddr {
+        SocketAddr::new(
+            self.redacted_telemetry_server_ip.parse().expect("invalid redacted telemetry ip"),
+            self.redacted_telemetry_server_port,
+        )
+    }
+
+    pub fn full_telemetry_server_address(&self) -> SocketAddr {
+        SocketAddr::new(
+            self.full_telemetry_server_ip.parse().expect("invalid full telemetry ip"),
+            self.full_telemetry_server_port,
+        )
+    }
+
+    /// `None` when the exporter is disabled, so `telemetry::servers::prometheus::spawn` can
+    /// stay a no-op without the caller needing a separate enabled check.
+    pub fn prometheus_server_address(&self) -> Option<SocketAddr> {
+        if !self.prometheus_server_enable {
+            return None;
+        }
+        Some(SocketAddr::new(
+            self.prometheus_server_ip.parse().expect("invalid prometheus ip"),
+            self.prometheus_server_port,
+        ))
+    }
+
+    pub fn profile_frequency(&self) -> i32 {
+        self.profile_frequency
+    }
+
+    pub fn profile_duration(&self) -> Option<Duration> {
+        self.profile_duration.map(Duration::from_secs)
+    }
+
+    pub fn profile_output(&self) -> PathBuf {
+        self.profile_output.clone()
+    }
+
+    pub fn building_algorithm_name(&self) -> String {
+        self.building_algorithm.clone()
+    }
+
+    pub fn create_provider_factory(
+        &self,
+    ) -> eyre::Result<reth_provider::providers::BlockchainProvider<reth_db::DatabaseEnv>> {
+        let db = std::sync::Arc::new(reth_db::open_db_read_only(
+            &self.reth_datadir.join("db"),
+            reth_db::mdbx::DatabaseArguments::new(Default::default()),
+        )?);
+        let chain_spec = crate::utils::chain_spec::chain_value_parser(&self.chain)?;
+        reth_provider::providers::BlockchainProvider::new(db, chain_spec)
+            .map_err(|err| eyre::eyre!("failed to open reth provider: {err}"))
+    }
+
+    /// Sets up the global tracing subscriber and returns a handle that lets `SIGHUP` change
+    /// the active log level and/or format (JSON vs. plain) at runtime without restarting the
+    /// process.
+    pub fn setup_tracing_subscriber(&self) -> eyre::Result<LogReloadHandle> {
+        use tracing_subscriber::layer::SubscriberExt;
+
+        let env_filter = EnvFilter::try_new(&self.log_level)
+            .or_else(|_| EnvFilter::try_from_default_env())
+            .unwrap_or_else(|_| EnvFilter::new("info"));
+        let (filter_layer, filter_reload_handle) = reload::Layer::new(env_filter);
+
+        let initial_fmt_layer = LogReloadHandle::fmt_layer(self.log_json, self.log_color);
+        let (fmt_layer, fmt_reload_handle) = reload::Layer::new(initial_fmt_layer);
+
+        tracing_subscriber::registry()
+            .with(filter_layer)
+            .with(fmt_layer)
+            .try_init()
+            .map_err(|err| eyre::eyre!(err))?;
+
+        Ok(LogReloadHandle {
+            filter: filter_reload_handle,
+            format: fmt_reload_handle,
+            log_color: self.log_color,
+        })
+    }
+}
+
+/// Runtime-mutable L1/optimistic-mode settings, kept separate from [`BaseConfig`] the same
+/// way [`L1CliArgs`] is kept separate from [`BaseCliArgs`] on the CLI side.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct L1Config {
+    pub dry_run: bool,
+    pub dry_run_validation_url: Vec<String>,
+    pub optimistic_enabled: bool,
+    pub optimistic_max_bid_value_eth: String,
+    pub optimistic_prevalidate_optimistic_blocks: bool,
+    pub max_concurrent_seals: u64,
+    pub cl_node_url: Vec<String>,
+    pub genesis_fork_version: Option<String>,
+}
+
+impl Default for L1Config {
+    fn default() -> Self {
+        Self {
+            dry_run: false,
+            dry_run_validation_url: Vec::new(),
+            optimistic_enabled: false,
+            optimistic_max_bid_value_eth: "0.0".to_string(),
+            optimistic_prevalidate_optimistic_blocks: false,
+            max_concurrent_seals: 4,
+            cl_node_url: Vec::new(),
+            genesis_fork_version: None,
+        }
+    }
+}
+
+/// Applies CLI overrides (only the `Some` fields) on top of whatever was loaded from the TOML
+/// config file. CLI flags always win when present; omitted flags leave the file's value alone.
+pub trait MergeFromCli<Cli> {
+    fn merge(&mut self, cli: &Cli);
+}
+
+/// For CLI fields whose config counterpart is a plain (non-`Option`) value: a `Some` on the
+/// CLI unconditionally overwrites whatever the TOML file set.
+macro_rules! merge_opt {
+    ($target:expr, $cli:expr, $($field:ident),+ $(,)?) => {
+        $(
+            if let Some(value) = $cli.$field.clone() {
+                $target.$field = value;
+            }
+        )+
+    };
+}
+
+/// For CLI fields whose config counterpart stays `Option<_>` (no sensible non-optional
+/// default): a `Some` on the CLI is wrapped back into `Some` on the config.
+macro_rules! merge_opt_passthrough {
+    ($target:expr, $cli:expr, $($field:ident),+ $(,)?) => {
+        $(
+            if let Some(value) = $cli.$field.clone() {
+                $target.$field = Some(value);
+            }
+        )+
+    };
+}
+
+impl MergeFromCli<BaseCliArgs> for BaseConfig {
+    fn merge(&mut self, cli: &BaseCliArgs) {
+        merge_opt!(
+            self,
+            cli,
+            log_json,
+            log_level,
+            full_telemetry_server_port,
+            full_telemetry_server_ip,
+            redacted_telemetry_server_port,
+            redacted_telemetry_server_ip,
+            log_color,
+            log_enable_dynamic,
+            jsonrpc_server_port,
+            jsonrpc_server_ip,
+            ignore_cancellable_orders,
+            ignore_blobs,
+            chain,
+            reth_datadir,
+            profile_frequency,
+            profile_output,
+            prometheus_server_enable,
+            prometheus_server_port,
+            prometheus_server_ip,
+        );
+        merge_opt_passthrough!(
+            self,
+            cli,
+            error_storage_path,
+            coinbase_secret_key,
+            flashbots_db,
+            profile_duration,
+            alert_webhook_url,
+            alert_matrix_homeserver_url,
+            alert_matrix_room,
+            alert_matrix_token,
+        );
+    }
+}
+
+impl MergeFromCli<L1CliArgs> for L1Config {
+    fn merge(&mut self, cli: &L1CliArgs) {
+        merge_opt!(
+            self,
+            cli,
+            dry_run,
+            optimistic_enabled,
+            optimistic_max_bid_value_eth,
+            optimistic_prevalidate_optimistic_blocks,
+            max_concurrent_seals,
+        );
+        merge_opt_passthrough!(self, cli, genesis_fork_version);
+        if let Some(dry_run_validation_url) = cli.dry_run_validation_url.clone() {
+            self.dry_run_validation_url = dry_run_validation_url;
+        }
+        if let Some(cl_node_url) = cli.cl_node_url.clone() {
+            self.cl_node_url = cl_node_url;
+        }
+    }
+}
+
+pub fn load_config_toml_and_env<ConfigType: DeserializeOwned>(
+    config_path: PathBuf,
+) -> eyre::Result<ConfigType> {
+    let config_text = std::fs::read_to_string(&config_path).map_err(|err| {
+        eyre::eyre!("failed to read config file {}: {err}", config_path.display())
+    })?;
+    toml::from_str(&config_text)
+        .map_err(|err| eyre::eyre!("failed to parse config file {}: {err}", config_path.display()))
+}
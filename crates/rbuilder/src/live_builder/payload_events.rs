@@ -0,0 +1,15 @@
+//! Slot-data sourcing: turns incoming MEV-Boost `get_payload`/`get_header` proposer duties into
+//! the slot events the builder loop reacts to.
+
+/// Default `SlotSource` for [`super::LiveBuilder`], driven by polling connected CL nodes for
+/// upcoming proposer duties.
+#[derive(Debug, Clone)]
+pub struct MevBoostSlotDataGenerator {
+    cl_node_urls: Vec<String>,
+}
+
+impl MevBoostSlotDataGenerator {
+    pub fn new(cl_node_urls: Vec<String>) -> Self {
+        Self { cl_node_urls }
+    }
+}
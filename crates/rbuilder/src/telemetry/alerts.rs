@@ -0,0 +1,277 @@
+//! Push alerting for builder lifecycle and failure events.
+//!
+//! This is intentionally separate from `telemetry::servers`: those servers expose pull-based
+//! metrics/logs for operators to scrape, while this module pushes a small number of
+//! high-signal events (startup/shutdown, repeated sealing failures, missed slots, bid
+//! rejections, optimistic bid-value breaches) to external sinks so operators don't have to
+//! watch a dashboard to notice something is wrong.
+//!
+//! Only redaction-safe summaries are ever sent, so this can run unmodified on TDX builders.
+
+use std::{
+    sync::atomic::{AtomicU64, Ordering},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use reqwest::Client;
+use serde::Serialize;
+use tokio::sync::mpsc;
+use tracing::{error, warn};
+
+/// A redaction-safe summary of a builder lifecycle or failure event.
+///
+/// Variants intentionally avoid carrying full order/bundle contents, raw transactions, or
+/// anything else that would be unsafe to surface on a TDX builder's alert channel.
+#[derive(Debug, Clone, Serialize)]
+#[serde(tag = "kind")]
+pub enum AlertEvent {
+    BuilderStarted { version: String },
+    BuilderShutdown { reason: String },
+    BlockSealingFailed { block_number: u64, consecutive_failures: u64, error: String },
+    SlotMissed { slot: u64 },
+    BidRejected { block_number: u64, reason: String },
+    OptimisticBidValueBreach { block_number: u64, bid_value_eth: String, max_allowed_eth: String },
+}
+
+impl AlertEvent {
+    fn summary(&self) -> String {
+        match self {
+            AlertEvent::BuilderStarted { version } => {
+                format!(":white_check_mark: rbuilder started (version {version})")
+            }
+            AlertEvent::BuilderShutdown { reason } => {
+                format!(":octagonal_sign: rbuilder shutting down: {reason}")
+            }
+            AlertEvent::BlockSealingFailed { block_number, consecutive_failures, error } => {
+                format!(
+                    ":rotating_light: block {block_number} failed to seal \
+                     ({consecutive_failures} consecutive failures): {error}"
+                )
+            }
+            AlertEvent::SlotMissed { slot } => {
+                format!(":warning: missed slot {slot}")
+            }
+            AlertEvent::BidRejected { block_number, reason } => {
+                format!(":no_entry: bid for block {block_number} rejected: {reason}")
+            }
+            AlertEvent::OptimisticBidValueBreach {
+                block_number,
+                bid_value_eth,
+                max_allowed_eth,
+            } => {
+                format!(
+                    ":rotating_light: optimistic bid for block {block_number} of \
+                     {bid_value_eth} ETH exceeds max {max_allowed_eth} ETH"
+                )
+            }
+        }
+    }
+}
+
+/// Handle used to push events into the alerting pipeline from anywhere in the builder.
+///
+/// Cloning is cheap (it's a channel sender); sends are fire-and-forget so callers on the
+/// hot path never block on alert delivery.
+#[derive(Debug, Clone)]
+pub struct AlertSender {
+    tx: mpsc::UnboundedSender<AlertEvent>,
+}
+
+impl AlertSender {
+    pub fn send(&self, event: AlertEvent) {
+        if self.tx.send(event).is_err() {
+            warn!("alert channel closed, dropping alert event");
+        }
+    }
+}
+
+/// Where alert events get delivered. A plain enum (rather than `Box<dyn Trait>`) since async
+/// trait methods aren't object-safe without boxing every call; match-based dispatch over a
+/// small, closed set of sink kinds is simpler here.
+enum AlertSink {
+    Webhook(WebhookSink),
+    Matrix(MatrixSink),
+}
+
+impl AlertSink {
+    async fn notify(&self, event: &AlertEvent) {
+        match self {
+            AlertSink::Webhook(sink) => sink.notify(event).await,
+            AlertSink::Matrix(sink) => sink.notify(event).await,
+        }
+    }
+}
+
+/// Generic JSON webhook sink: POSTs `{"kind": ..., "summary": ..., ...}` to a configured URL.
+struct WebhookSink {
+    client: Client,
+    url: String,
+}
+
+impl WebhookSink {
+    async fn notify(&self, event: &AlertEvent) {
+        #[derive(Serialize)]
+        struct Payload<'a> {
+            summary: String,
+            #[serde(flatten)]
+            event: &'a AlertEvent,
+        }
+        let payload = Payload { summary: event.summary(), event };
+        if let Err(err) = self.client.post(&self.url).json(&payload).send().await {
+            error!(?err, "failed to deliver alert to webhook");
+        }
+    }
+}
+
+/// Matrix `m.room.message` sink, posting plain-text notices via the client-server API.
+struct MatrixSink {
+    client: Client,
+    homeserver_url: String,
+    room_id: String,
+    access_token: String,
+}
+
+/// Monotonic counter used to build Matrix transaction IDs, so repeated sends from the same
+/// process never collide without pulling in a UUID dependency for it.
+static MATRIX_TXN_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+impl MatrixSink {
+    async fn notify(&self, event: &AlertEvent) {
+        #[derive(Serialize)]
+        struct MatrixMessage {
+            msgtype: &'static str,
+            body: String,
+        }
+        let since_epoch =
+            SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_micros();
+        let sequence = MATRIX_TXN_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let txn_id = format!("rbuilder-{since_epoch}-{sequence}");
+        let url = format!(
+            "{}/_matrix/client/v3/rooms/{}/send/m.room.message/{}",
+            self.homeserver_url, self.room_id, txn_id
+        );
+        let message = MatrixMessage { msgtype: "m.notice", body: event.summary() };
+        let result = self
+            .client
+            .put(&url)
+            .bearer_auth(&self.access_token)
+            .json(&message)
+            .send()
+            .await;
+        if let Err(err) = result {
+            error!(?err, "failed to deliver alert to matrix room");
+        }
+    }
+}
+
+/// Configuration for the alerting subsystem, derived from [`BaseCliArgs`](crate::live_builder::cli::BaseCliArgs).
+#[derive(Debug, Clone, Default)]
+pub struct AlertConfig {
+    pub webhook_url: Option<String>,
+    pub matrix_homeserver_url: Option<String>,
+    pub matrix_room: Option<String>,
+    pub matrix_token: Option<String>,
+}
+
+impl AlertConfig {
+    fn is_configured(&self) -> bool {
+        self.webhook_url.is_some()
+            || (self.matrix_room.is_some() && self.matrix_token.is_some())
+    }
+}
+
+/// Spawns the alerting task and returns a sender used to publish events.
+///
+/// If no sinks are configured, still returns a working sender whose events are simply
+/// dropped, so callers don't need to special-case the disabled state.
+pub fn spawn(config: AlertConfig) -> AlertSender {
+    let (tx, mut rx) = mpsc::unbounded_channel::<AlertEvent>();
+
+    if !config.is_configured() {
+        // Drain silently so senders never block/error even though nothing is wired up.
+        tokio::spawn(async move { while rx.recv().await.is_some() {} });
+        return AlertSender { tx };
+    }
+
+    let mut sinks: Vec<AlertSink> = Vec::new();
+    let client = Client::builder()
+        .timeout(Duration::from_secs(10))
+        .build()
+        .unwrap_or_default();
+
+    if let Some(url) = config.webhook_url {
+        sinks.push(AlertSink::Webhook(WebhookSink { client: client.clone(), url }));
+    }
+    if let (Some(room_id), Some(access_token)) = (config.matrix_room, config.matrix_token) {
+        sinks.push(AlertSink::Matrix(MatrixSink {
+            client: client.clone(),
+            homeserver_url: config
+                .matrix_homeserver_url
+                .unwrap_or_else(|| "https://matrix.org".to_string()),
+            room_id,
+            access_token,
+        }));
+    }
+
+    tokio::spawn(async move {
+        while let Some(event) = rx.recv().await {
+            for sink in &sinks {
+                sink.notify(&event).await;
+            }
+        }
+    });
+
+    AlertSender { tx }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn summary_includes_the_relevant_fields() {
+        let summary = AlertEvent::BlockSealingFailed {
+            block_number: 123,
+            consecutive_failures: 4,
+            error: "ran out of gas".to_string(),
+        }
+        .summary();
+        assert!(summary.contains("123"));
+        assert!(summary.contains('4'));
+        assert!(summary.contains("ran out of gas"));
+
+        let summary = AlertEvent::OptimisticBidValueBreach {
+            block_number: 7,
+            bid_value_eth: "1.5".to_string(),
+            max_allowed_eth: "1.0".to_string(),
+        }
+        .summary();
+        assert!(summary.contains("1.5"));
+        assert!(summary.contains("1.0"));
+    }
+
+    #[test]
+    fn is_configured_requires_a_complete_sink() {
+        assert!(!AlertConfig::default().is_configured());
+
+        assert!(AlertConfig {
+            webhook_url: Some("https://example.com/hook".to_string()),
+            ..Default::default()
+        }
+        .is_configured());
+
+        // A matrix room with no access token isn't a usable sink.
+        assert!(!AlertConfig {
+            matrix_room: Some("!room:example.com".to_string()),
+            ..Default::default()
+        }
+        .is_configured());
+
+        assert!(AlertConfig {
+            matrix_room: Some("!room:example.com".to_string()),
+            matrix_token: Some("token".to_string()),
+            ..Default::default()
+        }
+        .is_configured());
+    }
+}
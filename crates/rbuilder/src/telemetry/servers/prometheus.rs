@@ -0,0 +1,77 @@
+//! Prometheus text-exposition endpoint for builder-domain metrics.
+//!
+//! Unlike `telemetry::servers::redacted`/`full`, which serve the builder's bespoke
+//! telemetry/log format, this exposes a standard `/metrics` endpoint so operators can scrape
+//! the builder from existing Prometheus-based monitoring. The metric set mirrors the redacted
+//! server's safety bar: only aggregate counts/latencies, nothing that identifies individual
+//! orders, bundles, or searchers, so it is safe to run on TDX builders.
+
+use std::{net::SocketAddr, sync::OnceLock};
+
+use axum::{routing::get, Router};
+use metrics_exporter_prometheus::{PrometheusBuilder, PrometheusHandle};
+use tracing::info;
+
+static PROMETHEUS_HANDLE: OnceLock<PrometheusHandle> = OnceLock::new();
+
+/// Registers the descriptions Prometheus shows alongside each metric on `/metrics`. Only
+/// needs to run once per process; called from `spawn` (metrics macros expand to runtime
+/// statements, so they can't live at module scope the way `static`s can).
+fn describe_metrics() {
+    metrics::describe_counter!("rbuilder_blocks_built_total", "Total blocks built");
+    metrics::describe_histogram!(
+        "rbuilder_sealing_latency_seconds",
+        "Latency of block sealing, in seconds"
+    );
+    metrics::describe_histogram!(
+        "rbuilder_simulation_duration_seconds",
+        "Latency of individual order simulations, in seconds"
+    );
+    metrics::describe_counter!("rbuilder_bids_submitted_total", "Total bids submitted to relays");
+    metrics::describe_counter!("rbuilder_bids_rejected_total", "Total bids rejected by relays");
+    metrics::describe_gauge!(
+        "rbuilder_current_best_bid_value_wei",
+        "Value of the current best bid for the in-flight slot, in wei"
+    );
+    metrics::describe_counter!(
+        "rbuilder_sealing_failures_total",
+        "Total block-sealing failures"
+    );
+    metrics::describe_counter!("rbuilder_slots_missed_total", "Total slots with no block submitted");
+}
+
+/// Spawns the Prometheus `/metrics` server. A no-op if `addr` is `None` (i.e. the operator
+/// left the exporter disabled via `prometheus_server_enable`).
+pub async fn spawn(addr: Option<SocketAddr>) -> eyre::Result<()> {
+    let Some(addr) = addr else {
+        return Ok(());
+    };
+
+    let handle = match PROMETHEUS_HANDLE.get() {
+        Some(handle) => handle.clone(),
+        None => {
+            let handle = PrometheusBuilder::new().install_recorder()?;
+            describe_metrics();
+            PROMETHEUS_HANDLE.set(handle.clone()).ok();
+            handle
+        }
+    };
+
+    let router = Router::new().route(
+        "/metrics",
+        get({
+            let handle = handle.clone();
+            move || async move { handle.render() }
+        }),
+    );
+
+    let listener = tokio::net::TcpListener::bind(addr).await?;
+    info!("Prometheus metrics server listening on {addr}");
+    tokio::spawn(async move {
+        if let Err(err) = axum::serve(listener, router).await {
+            tracing::error!(?err, "prometheus metrics server exited");
+        }
+    });
+
+    Ok(())
+}